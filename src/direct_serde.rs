@@ -1,23 +1,121 @@
+use serde::de::Error;
 use serde::{de::*, ser::*};
+use std::fmt;
 use std::time::Duration;
 
 use crate::*;
 
 /// ISO 8601 serialization format for `std::time::Duration`.
+///
+/// When the serializer is not human-readable (e.g. bincode, MessagePack),
+/// a compact `(seconds, subsec_nanos)` tuple is emitted instead.
 pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    serializer.serialize_str(to_iso8601(duration).as_str())
+    if serializer.is_human_readable() {
+        serializer.serialize_str(to_iso8601(duration).as_str())
+    } else {
+        let mut tup = serializer.serialize_tuple(2)?;
+        tup.serialize_element(&duration.as_secs())?;
+        tup.serialize_element(&duration.subsec_nanos())?;
+        tup.end()
+    }
 }
 
 /// ISO 8601 deserialization format for `std::time::Duration`.
+///
+/// When the deserializer is not human-readable (e.g. bincode, MessagePack),
+/// a compact `(seconds, subsec_nanos)` tuple is expected instead.
 pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let value = String::deserialize(deserializer)?;
+    if deserializer.is_human_readable() {
+        let value = String::deserialize(deserializer)?;
 
-    from_iso8601(&value)
-        .ok_or_else(|| D::Error::invalid_value(Unexpected::Str(&value), &"PdDThHmMsS"))
+        from_iso8601(&value)
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Str(&value), &"PdDThHmMsS"))
+    } else {
+        deserializer.deserialize_tuple(2, DurationTupleVisitor)
+    }
+}
+
+pub(crate) struct DurationTupleVisitor;
+
+impl<'de> Visitor<'de> for DurationTupleVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a (seconds, subsec_nanos) tuple")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let secs: u64 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let subsec_nanos: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok(Duration::new(secs, subsec_nanos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Tester(#[serde(with = "crate")] Duration);
+
+    #[test]
+    fn ser_dur() {
+        let dur = serde_json::to_string(&Tester(Duration::new(1, 500_000_000))).unwrap();
+        assert_eq!(dur, "\"PT1.5S\"".to_string());
+    }
+
+    #[test]
+    fn de_dur() {
+        let Tester(dur) = serde_json::from_str("\"PT1.5S\"").unwrap();
+        assert_eq!(dur, Duration::new(1, 500_000_000));
+    }
+
+    /// A minimal [`SeqAccess`] over a fixed `(seconds, subsec_nanos)` pair,
+    /// used to drive `DurationTupleVisitor` directly for the
+    /// non-human-readable path without a real binary format (e.g. bincode)
+    /// available as a dev-dependency.
+    struct TupleSeqAccess(std::vec::IntoIter<serde_json::Value>);
+
+    impl<'de> SeqAccess<'de> for TupleSeqAccess {
+        type Error = serde_json::Error;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            match self.0.next() {
+                Some(value) => seed.deserialize(value).map(Some),
+                None => Ok(None),
+            }
+        }
+    }
+
+    #[test]
+    fn round_trips_tuple_encoding() {
+        let duration = Duration::new(1, 500_000_000);
+
+        let seq = TupleSeqAccess(
+            vec![
+                serde_json::Value::from(duration.as_secs()),
+                serde_json::Value::from(duration.subsec_nanos()),
+            ]
+            .into_iter(),
+        );
+
+        let decoded = DurationTupleVisitor.visit_seq(seq).unwrap();
+        assert_eq!(decoded, duration);
+    }
 }
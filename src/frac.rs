@@ -0,0 +1,65 @@
+//! Shared fractional-unit deserialization support used by the millisecond,
+//! microsecond and nanosecond duration formats.
+
+use serde::de::*;
+use std::fmt;
+use std::time::Duration;
+
+/// Visits an integer, float, or numeric string denominated in `scale` units
+/// per second and reconstructs the corresponding [`Duration`], rejecting
+/// negative or out-of-range values rather than silently clamping them.
+pub(crate) struct FracVisitor {
+    pub(crate) scale: f64,
+}
+
+impl FracVisitor {
+    fn scaled_duration<E>(&self, value: f64) -> Result<Duration, E>
+    where
+        E: Error,
+    {
+        let secs = value / self.scale;
+        if !secs.is_finite() || secs < 0.0 || secs >= Duration::MAX.as_secs_f64() {
+            return Err(E::invalid_value(Unexpected::Float(value), &self));
+        }
+        Ok(Duration::from_secs_f64(secs))
+    }
+}
+
+impl<'de> Visitor<'de> for FracVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a number or numeric string")
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.scaled_duration(value)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.scaled_duration(value as f64)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.scaled_duration(value as f64)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        value
+            .parse::<f64>()
+            .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+            .and_then(|value| self.scaled_duration(value))
+    }
+}
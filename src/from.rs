@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+/// Create a [`Duration`] from a number of weeks.
+pub fn weeks(weeks: u64) -> Duration {
+    Duration::from_secs(weeks * 604_800)
+}
+
+/// Create a [`Duration`] from a number of days.
+pub fn days(days: u64) -> Duration {
+    Duration::from_secs(days * 86_400)
+}
+
+/// Create a [`Duration`] from a number of hours.
+pub fn hours(hours: u64) -> Duration {
+    Duration::from_secs(hours * 3_600)
+}
+
+/// Create a [`Duration`] from a number of minutes.
+pub fn minutes(minutes: u64) -> Duration {
+    Duration::from_secs(minutes * 60)
+}
+
+/// Serializes a [`Duration`] to its ISO 8601 representation, e.g. `PT1H30M0S`,
+/// emitting fractional seconds (e.g. `PT1.5S`) when the duration has a
+/// nonzero sub-second component.
+pub fn to_iso8601(duration: &Duration) -> String {
+    let total_secs = duration.as_secs();
+    let days = total_secs / 86_400;
+    let hours = (total_secs % 86_400) / 3_600;
+    let minutes = (total_secs % 3_600) / 60;
+    let seconds = total_secs % 60;
+    let subsec_nanos = duration.subsec_nanos();
+
+    let mut iso = String::from("P");
+    if days > 0 {
+        iso.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 || subsec_nanos > 0 || days == 0 {
+        iso.push('T');
+        if hours > 0 {
+            iso.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            iso.push_str(&format!("{}M", minutes));
+        }
+        if subsec_nanos > 0 {
+            let fraction = format!("{:09}", subsec_nanos);
+            let fraction = fraction.trim_end_matches('0');
+            iso.push_str(&format!("{}.{}S", seconds, fraction));
+        } else {
+            iso.push_str(&format!("{}S", seconds));
+        }
+    }
+    iso
+}
+
+/// Serializes a [`Duration`] to its ISO 8601 representation, prefixing a
+/// leading `-` when `negative` is `true` (and the duration is nonzero).
+pub fn to_iso8601_signed(duration: &Duration, negative: bool) -> String {
+    if negative && !duration.is_zero() {
+        format!("-{}", to_iso8601(duration))
+    } else {
+        to_iso8601(duration)
+    }
+}
+
+/// Parses a [`Duration`] from its ISO 8601 representation in the grammar
+/// accepted by [`from_iso8601`], allowing an optional leading `-`/`+` sign. Returns whether
+/// the value was negative alongside its magnitude.
+pub fn from_iso8601_signed(value: &str) -> Option<(bool, Duration)> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    from_iso8601(value).map(|duration| (negative, duration))
+}
+
+/// Parses a [`Duration`] from its ISO 8601 representation of the shape
+/// `PnWnDTnHnMnS`. Each field accepts a fractional value using either `.` or
+/// `,` as the decimal separator (e.g. `PT1.5S`, `PT0,5S`). The fields are
+/// summed as an `f64` number of seconds, so the result is exact to the
+/// nanosecond only for totals below about 2^23 seconds (roughly 97 days);
+/// longer durations are rounded to the nearest representable `f64` value.
+pub fn from_iso8601(value: &str) -> Option<Duration> {
+    let value = value.strip_prefix('P')?;
+    let (mut date, time) = match value.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+
+    let mut total_secs = 0.0_f64;
+    let mut matched_any = false;
+
+    if let Some(idx) = date.find('W') {
+        total_secs += parse_field(&date[..idx])? * 604_800.0;
+        date = &date[idx + 1..];
+        matched_any = true;
+    }
+    if !date.is_empty() {
+        total_secs += parse_field(date.strip_suffix('D')?)? * 86_400.0;
+        matched_any = true;
+    }
+
+    if let Some(mut time) = time {
+        if let Some(idx) = time.find('H') {
+            total_secs += parse_field(&time[..idx])? * 3_600.0;
+            time = &time[idx + 1..];
+            matched_any = true;
+        }
+        if let Some(idx) = time.find('M') {
+            total_secs += parse_field(&time[..idx])? * 60.0;
+            time = &time[idx + 1..];
+            matched_any = true;
+        }
+        if let Some(idx) = time.find('S') {
+            total_secs += parse_field(&time[..idx])?;
+            time = &time[idx + 1..];
+            matched_any = true;
+        }
+        if !time.is_empty() {
+            return None;
+        }
+    }
+
+    if !matched_any || total_secs < 0.0 || total_secs >= Duration::MAX.as_secs_f64() {
+        return None;
+    }
+
+    Some(Duration::from_secs_f64(total_secs))
+}
+
+/// Parses a single ISO 8601 duration field value, accepting both `.` and
+/// `,` as the decimal separator. Rejects non-finite (`nan`, `inf`) and
+/// negative values, since Rust's float parser would otherwise accept them.
+fn parse_field(value: &str) -> Option<f64> {
+    if value.is_empty() {
+        return None;
+    }
+    let value: f64 = value.replace(',', ".").parse().ok()?;
+    if !value.is_finite() || value < 0.0 {
+        return None;
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_weeks() {
+        assert_eq!(from_iso8601("P1W"), Some(Duration::from_secs(604_800)));
+    }
+
+    #[test]
+    fn parses_fractional_seconds() {
+        assert_eq!(from_iso8601("PT0.001S"), Some(Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn parses_comma_decimal() {
+        assert_eq!(from_iso8601("PT0,5S"), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn round_trips_fractional_seconds() {
+        let duration = Duration::new(1, 500_000_000);
+        assert_eq!(to_iso8601(&duration), "PT1.5S");
+        assert_eq!(from_iso8601(&to_iso8601(&duration)), Some(duration));
+    }
+
+    #[test]
+    fn rejects_non_finite_fields() {
+        assert_eq!(from_iso8601("PTnanS"), None);
+        assert_eq!(from_iso8601("PT1e400S"), None);
+        assert_eq!(from_iso8601("PTinfS"), None);
+    }
+
+    #[test]
+    fn rejects_fields_that_overflow_duration() {
+        assert_eq!(from_iso8601("PT1e300S"), None);
+    }
+}
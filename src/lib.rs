@@ -5,12 +5,33 @@
 mod from;
 pub use from::*;
 
+mod macros;
+pub(crate) use macros::{duration_opt_module, duration_unit_module};
+
 mod direct_serde;
 pub use direct_serde::*;
 
 /// serde for `Option<Duration>`
 pub mod opt;
 
+/// serde for `Duration` as an integer number of seconds, as used by APIs
+/// that encode durations as a plain number rather than an ISO 8601 string
+pub mod seconds;
+
+mod frac;
+
+/// serde for `Duration` as an integer (or, via `with_frac`, fractional)
+/// number of milliseconds
+pub mod milliseconds;
+
+/// serde for `Duration` as an integer (or, via `with_frac`, fractional)
+/// number of microseconds
+pub mod microseconds;
+
+/// serde for `Duration` as an integer (or, via `with_frac`, fractional)
+/// number of nanoseconds
+pub mod nanoseconds;
+
 #[cfg(feature = "serde_with")]
 /// `serde_as` notation from `serde_with` crate
 pub mod serde_as;
@@ -0,0 +1,247 @@
+/// Generates the `serialize`/`deserialize` pair for an `Option<Duration>`,
+/// delegating to the sibling `$base` module (e.g. `"super"` for a module
+/// nested under the format it wraps, or a fully-qualified path such as
+/// `"crate::direct_serde"`) for the `Some` case and emitting `null`/`None`
+/// for the absent case. Patterned after the `opt.rs` module wrapping
+/// `direct_serde`, so every format gets a consistent `::opt` submodule for
+/// free.
+macro_rules! duration_opt_module {
+    ($base:literal) => {
+        use serde::{de::*, ser::*, Deserialize, Serialize};
+        use std::time::Duration;
+
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = $base)] Duration);
+
+        /// Serializes an `Option<Duration>`, or `null` when absent.
+        pub fn serialize<S>(opt: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            if let Some(duration) = opt {
+                serializer.serialize_some(&Wrapper(*duration))
+            } else {
+                serializer.serialize_none()
+            }
+        }
+
+        /// Deserializes an `Option<Duration>`, or `None` from `null`.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Option::<Wrapper>::deserialize(deserializer)
+                .map(|opt| opt.map(|Wrapper(duration)| duration))
+        }
+    };
+}
+
+pub(crate) use duration_opt_module;
+
+/// Generates the shared body of an integer-and-fractional duration serde
+/// format module (used by `milliseconds`, `microseconds`, and
+/// `nanoseconds`, which differ only in scale, unit name, and the
+/// `Duration::from_*`/`as_*` methods they call).
+///
+/// Arguments, in order: the plural unit name for docs/error messages; the
+/// number of units per second; the `Duration::from_*` and `Duration::as_*`
+/// method names; the integer `Visitor` type name; an integer test value and
+/// its expected JSON; a `with_frac` test value and its expected JSON; a
+/// JSON string to deserialize for the `with_frac` deserialize test; and the
+/// nanosecond count it's expected to produce.
+macro_rules! duration_unit_module {
+    (
+        $unit_plural:literal,
+        $scale:expr,
+        $from_unit:ident,
+        $as_unit:ident,
+        $visitor:ident,
+        $int_value:expr,
+        $int_json:literal,
+        $frac_value:expr,
+        $frac_json:literal,
+        $frac_de_json:literal,
+        $frac_de_nanos:expr
+    ) => {
+        use crate::frac::FracVisitor;
+        use serde::de::Error;
+        use serde::{de::*, ser::*};
+        use std::convert::TryFrom;
+        use std::fmt;
+        use std::time::Duration;
+
+        const SCALE: f64 = $scale;
+
+        #[doc = concat!(
+            "Serializes a [`Duration`] as the integer number of whole ",
+            $unit_plural,
+            " it spans.",
+        )]
+        ///
+        #[doc = concat!(
+            "Fails if the duration is too large to fit in a `u64` number of ",
+            $unit_plural,
+            ", rather than silently truncating.",
+        )]
+        pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let scaled = u64::try_from(duration.$as_unit()).map_err(|_| {
+                S::Error::custom(format!(
+                    concat!("duration {:?} exceeds u64 ", $unit_plural),
+                    duration
+                ))
+            })?;
+            serializer.serialize_u64(scaled)
+        }
+
+        #[doc = concat!(
+            "Deserializes a [`Duration`] from an integer number of whole ",
+            $unit_plural,
+            ".",
+        )]
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_u64($visitor)
+        }
+
+        pub(crate) struct $visitor;
+
+        impl<'de> Visitor<'de> for $visitor {
+            type Value = Duration;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("an integer number of ", $unit_plural))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Duration::$from_unit(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                if value < 0 {
+                    return Err(E::invalid_value(Unexpected::Signed(value), &self));
+                }
+                Ok(Duration::$from_unit(value as u64))
+            }
+        }
+
+        #[doc = concat!(
+            "serde for `Option<Duration>` as an integer number of ",
+            $unit_plural,
+            ".",
+        )]
+        pub mod opt {
+            crate::duration_opt_module!("super");
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[derive(serde::Serialize, serde::Deserialize)]
+            struct Tester(#[serde(with = "super")] Duration);
+
+            #[test]
+            fn ser_dur() {
+                let dur = serde_json::to_string(&Tester(Duration::$from_unit($int_value))).unwrap();
+                assert_eq!(dur, $int_json.to_string());
+            }
+
+            #[test]
+            fn de_dur() {
+                let Tester(dur) = serde_json::from_str($int_json).unwrap();
+                assert_eq!(dur, Duration::$from_unit($int_value));
+            }
+        }
+
+        #[doc = concat!(
+            "Fractional ",
+            $unit_plural,
+            " serialization format for `std::time::Duration`, serializing as ",
+            "an `f64` and accepting integers, floats, or numeric strings on ",
+            "deserialize.",
+        )]
+        pub mod with_frac {
+            use super::{FracVisitor, SCALE};
+            use serde::{de::*, ser::*};
+            use std::time::Duration;
+
+            #[doc = concat!(
+                "Serializes a [`Duration`] as a floating point number of ",
+                $unit_plural,
+                ".",
+            )]
+            pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_f64(duration.as_secs_f64() * SCALE)
+            }
+
+            #[doc = concat!(
+                "Deserializes a [`Duration`] from a floating point (or numeric ",
+                "string) number of ",
+                $unit_plural,
+                ".",
+            )]
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_any(FracVisitor { scale: SCALE })
+            }
+
+            #[doc = concat!(
+                "serde for `Option<Duration>` as a floating point number of ",
+                $unit_plural,
+                ".",
+            )]
+            pub mod opt {
+                crate::duration_opt_module!("super");
+            }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+
+                #[derive(serde::Serialize, serde::Deserialize)]
+                struct Tester(#[serde(with = "super")] Duration);
+
+                #[test]
+                fn ser_dur() {
+                    let dur =
+                        serde_json::to_string(&Tester(Duration::$from_unit($frac_value))).unwrap();
+                    assert_eq!(dur, $frac_json.to_string());
+                }
+
+                #[test]
+                fn de_dur() {
+                    let Tester(dur) = serde_json::from_str($frac_de_json).unwrap();
+                    assert_eq!(dur, Duration::from_nanos($frac_de_nanos));
+                }
+
+                #[test]
+                fn de_dur_rejects_negative() {
+                    assert!(serde_json::from_str::<Tester>("-5").is_err());
+                }
+
+                #[test]
+                fn de_dur_rejects_overflow() {
+                    assert!(serde_json::from_str::<Tester>("1e300").is_err());
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use duration_unit_module;
@@ -0,0 +1,16 @@
+//! Integer and fractional microseconds serialization formats for
+//! `std::time::Duration`.
+
+crate::duration_unit_module!(
+    "microseconds",
+    1_000_000.0,
+    from_micros,
+    as_micros,
+    MicrosVisitor,
+    1_500,
+    "1500",
+    1_500,
+    "1500.0",
+    "1500.5",
+    1_500_500
+);
@@ -0,0 +1,16 @@
+//! Integer and fractional milliseconds serialization formats for
+//! `std::time::Duration`.
+
+crate::duration_unit_module!(
+    "milliseconds",
+    1_000.0,
+    from_millis,
+    as_millis,
+    MillisVisitor,
+    1_500,
+    "1500",
+    1_500,
+    "1500.0",
+    "1500.5",
+    1_500_500_000
+);
@@ -0,0 +1,16 @@
+//! Integer and fractional nanoseconds serialization formats for
+//! `std::time::Duration`.
+
+crate::duration_unit_module!(
+    "nanoseconds",
+    1_000_000_000.0,
+    from_nanos,
+    as_nanos,
+    NanosVisitor,
+    1_500,
+    "1500",
+    1_500,
+    "1500.0",
+    "1500.0",
+    1_500
+);
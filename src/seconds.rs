@@ -0,0 +1,102 @@
+//! Integer seconds serialization format for `std::time::Duration`, as used by
+//! APIs (e.g. Mastodon-style) that encode durations as a plain number of
+//! seconds rather than an ISO 8601 string.
+
+use serde::de::Error;
+use serde::{de::*, ser::*};
+use std::fmt;
+use std::time::Duration;
+
+/// Serializes a [`Duration`] as the integer number of whole seconds it spans.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u64(duration.as_secs())
+}
+
+/// Deserializes a [`Duration`] from an integer number of whole seconds.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_u64(SecondsVisitor)
+}
+
+pub(crate) struct SecondsVisitor;
+
+impl<'de> Visitor<'de> for SecondsVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an integer number of seconds")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(Duration::from_secs(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if value < 0 {
+            return Err(E::invalid_value(Unexpected::Signed(value), &self));
+        }
+        Ok(Duration::from_secs(value as u64))
+    }
+}
+
+/// serde for `Option<Duration>` as an integer number of seconds.
+pub mod opt {
+    crate::duration_opt_module!("super");
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[derive(Serialize, Deserialize)]
+        struct Tester(#[serde(with = "super")] Option<Duration>);
+
+        #[test]
+        fn ser_opt_dur() {
+            let dur = serde_json::to_string(&Tester(Some(Duration::from_secs(1)))).unwrap();
+            assert_eq!(dur, "1".to_string());
+
+            let dur = serde_json::to_string(&Tester(None)).unwrap();
+            assert_eq!(dur, "null".to_string());
+        }
+
+        #[test]
+        fn de_opt_dur() {
+            let Tester(opt_dur) = serde_json::from_str("1").unwrap();
+            assert_eq!(opt_dur, Some(Duration::from_secs(1)));
+
+            let Tester(opt_dur) = serde_json::from_str("null").unwrap();
+            assert_eq!(opt_dur, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Tester(#[serde(with = "super")] Duration);
+
+    #[test]
+    fn ser_dur() {
+        let dur = serde_json::to_string(&Tester(Duration::from_secs(90))).unwrap();
+        assert_eq!(dur, "90".to_string());
+    }
+
+    #[test]
+    fn de_dur() {
+        let Tester(dur) = serde_json::from_str("90").unwrap();
+        assert_eq!(dur, Duration::from_secs(90));
+    }
+}
@@ -14,7 +14,14 @@ impl SerializeAs<std::time::Duration> for DurationWrapper {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&to_iso8601(duration))
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&to_iso8601(duration))
+        } else {
+            let mut tup = serializer.serialize_tuple(2)?;
+            tup.serialize_element(&duration.as_secs())?;
+            tup.serialize_element(&duration.subsec_nanos())?;
+            tup.end()
+        }
     }
 }
 
@@ -23,10 +30,14 @@ impl<'de> DeserializeAs<'de, std::time::Duration> for DurationWrapper {
     where
         D: Deserializer<'de>,
     {
-        let value = String::deserialize(deserializer)?;
+        if deserializer.is_human_readable() {
+            let value = String::deserialize(deserializer)?;
 
-        from_iso8601(&value)
-            .ok_or_else(|| D::Error::invalid_value(Unexpected::Str(&value), &"PdDThHmMsS"))
+            from_iso8601(&value)
+                .ok_or_else(|| D::Error::invalid_value(Unexpected::Str(&value), &"PdDThHmMsS"))
+        } else {
+            deserializer.deserialize_tuple(2, crate::direct_serde::DurationTupleVisitor)
+        }
     }
 }
 
@@ -36,13 +47,19 @@ impl SerializeAs<chrono::Duration> for DurationWrapper {
     where
         S: Serializer,
     {
-        let duration = duration.to_std().map_err(|_| {
-            S::Error::custom(format!(
-                "only positive duration supported for now but got {}",
-                duration
-            ))
+        let negative = *duration < chrono::Duration::zero();
+        let magnitude = duration.abs().to_std().map_err(|_| {
+            S::Error::custom(format!("duration magnitude out of range: {}", duration))
         })?;
-        serializer.serialize_str(&to_iso8601(&duration))
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&to_iso8601_signed(&magnitude, negative))
+        } else {
+            let mut tup = serializer.serialize_tuple(3)?;
+            tup.serialize_element(&negative)?;
+            tup.serialize_element(&magnitude.as_secs())?;
+            tup.serialize_element(&magnitude.subsec_nanos())?;
+            tup.end()
+        }
     }
 }
 
@@ -52,15 +69,295 @@ impl<'de> DeserializeAs<'de, chrono::Duration> for DurationWrapper {
     where
         D: Deserializer<'de>,
     {
-        let value = String::deserialize(deserializer)?;
+        let (negative, magnitude) = if deserializer.is_human_readable() {
+            let value = String::deserialize(deserializer)?;
+
+            from_iso8601_signed(&value)
+                .ok_or_else(|| D::Error::invalid_value(Unexpected::Str(&value), &"PdDThHmMsS"))?
+        } else {
+            deserializer.deserialize_tuple(3, SignedDurationTupleVisitor)?
+        };
+
+        let magnitude = chrono::Duration::from_std(magnitude).map_err(|_| {
+            D::Error::custom("duration magnitude out of range for chrono::Duration")
+        })?;
+
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+}
+
+#[cfg(feature = "chrono")]
+struct SignedDurationTupleVisitor;
+
+#[cfg(feature = "chrono")]
+impl<'de> Visitor<'de> for SignedDurationTupleVisitor {
+    type Value = (bool, std::time::Duration);
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a (negative, seconds, subsec_nanos) tuple")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let negative: bool = seq
+            .next_element()?
+            .ok_or_else(|| DeErr::invalid_length(0, &self))?;
+        let secs: u64 = seq
+            .next_element()?
+            .ok_or_else(|| DeErr::invalid_length(1, &self))?;
+        let subsec_nanos: u32 = seq
+            .next_element()?
+            .ok_or_else(|| DeErr::invalid_length(2, &self))?;
+        Ok((negative, std::time::Duration::new(secs, subsec_nanos)))
+    }
+}
+
+/// `serde_as` notation from `serde_with` crate for [`std::time::Duration`]
+/// and [`chrono::Duration`] with optional `chrono` feature, as an integer
+/// number of seconds
+pub struct DurationSeconds;
+
+impl SerializeAs<std::time::Duration> for DurationSeconds {
+    fn serialize_as<S>(duration: &std::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(duration.as_secs())
+    }
+}
+
+impl<'de> DeserializeAs<'de, std::time::Duration> for DurationSeconds {
+    fn deserialize_as<D>(deserializer: D) -> Result<std::time::Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u64(crate::seconds::SecondsVisitor)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl SerializeAs<chrono::Duration> for DurationSeconds {
+    fn serialize_as<S>(duration: &chrono::Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(duration.num_seconds())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<'de> DeserializeAs<'de, chrono::Duration> for DurationSeconds {
+    fn deserialize_as<D>(deserializer: D) -> Result<chrono::Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let seconds = i64::deserialize(deserializer)?;
+        Ok(chrono::Duration::seconds(seconds))
+    }
+}
+
+/// `serde_as` notation for [`std::time::Duration`] as an integer number of
+/// milliseconds
+pub struct DurationMilliseconds;
+
+impl SerializeAs<std::time::Duration> for DurationMilliseconds {
+    fn serialize_as<S>(duration: &std::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::milliseconds::serialize(duration, serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, std::time::Duration> for DurationMilliseconds {
+    fn deserialize_as<D>(deserializer: D) -> Result<std::time::Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u64(crate::milliseconds::MillisVisitor)
+    }
+}
+
+/// `serde_as` notation for [`std::time::Duration`] as a floating point
+/// number of milliseconds
+pub struct DurationMillisecondsWithFrac;
+
+impl SerializeAs<std::time::Duration> for DurationMillisecondsWithFrac {
+    fn serialize_as<S>(duration: &std::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(duration.as_secs_f64() * 1_000.0)
+    }
+}
+
+impl<'de> DeserializeAs<'de, std::time::Duration> for DurationMillisecondsWithFrac {
+    fn deserialize_as<D>(deserializer: D) -> Result<std::time::Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(crate::frac::FracVisitor { scale: 1_000.0 })
+    }
+}
+
+/// `serde_as` notation for [`std::time::Duration`] as an integer number of
+/// microseconds
+pub struct DurationMicroseconds;
+
+impl SerializeAs<std::time::Duration> for DurationMicroseconds {
+    fn serialize_as<S>(duration: &std::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::microseconds::serialize(duration, serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, std::time::Duration> for DurationMicroseconds {
+    fn deserialize_as<D>(deserializer: D) -> Result<std::time::Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u64(crate::microseconds::MicrosVisitor)
+    }
+}
 
-        match from_iso8601(&value) {
-            None => Err(D::Error::invalid_value(
-                Unexpected::Str(&value),
-                &"PdDThHmMsS",
-            )),
-            Some(duration) => chrono::Duration::from_std(duration)
-                .map_err(|_| D::Error::invalid_value(Unexpected::Str(&value), &"PdDThHmMsS")),
+/// `serde_as` notation for [`std::time::Duration`] as a floating point
+/// number of microseconds
+pub struct DurationMicrosecondsWithFrac;
+
+impl SerializeAs<std::time::Duration> for DurationMicrosecondsWithFrac {
+    fn serialize_as<S>(duration: &std::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(duration.as_secs_f64() * 1_000_000.0)
+    }
+}
+
+impl<'de> DeserializeAs<'de, std::time::Duration> for DurationMicrosecondsWithFrac {
+    fn deserialize_as<D>(deserializer: D) -> Result<std::time::Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(crate::frac::FracVisitor { scale: 1_000_000.0 })
+    }
+}
+
+/// `serde_as` notation for [`std::time::Duration`] as an integer number of
+/// nanoseconds
+pub struct DurationNanoseconds;
+
+impl SerializeAs<std::time::Duration> for DurationNanoseconds {
+    fn serialize_as<S>(duration: &std::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::nanoseconds::serialize(duration, serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, std::time::Duration> for DurationNanoseconds {
+    fn deserialize_as<D>(deserializer: D) -> Result<std::time::Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u64(crate::nanoseconds::NanosVisitor)
+    }
+}
+
+/// `serde_as` notation for [`std::time::Duration`] as a floating point
+/// number of nanoseconds
+pub struct DurationNanosecondsWithFrac;
+
+impl SerializeAs<std::time::Duration> for DurationNanosecondsWithFrac {
+    fn serialize_as<S>(duration: &std::time::Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f64(duration.as_secs_f64() * 1_000_000_000.0)
+    }
+}
+
+impl<'de> DeserializeAs<'de, std::time::Duration> for DurationNanosecondsWithFrac {
+    fn deserialize_as<D>(deserializer: D) -> Result<std::time::Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(crate::frac::FracVisitor { scale: 1_000_000_000.0 })
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod tests {
+    use super::*;
+    use serde_with::serde_as;
+
+    #[serde_as]
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Tester(#[serde_as(as = "DurationWrapper")] chrono::Duration);
+
+    #[test]
+    fn signed_chrono_duration_round_trips_human_readable() {
+        let negative = chrono::Duration::seconds(-90);
+        let json = serde_json::to_string(&Tester(negative)).unwrap();
+        assert_eq!(json, "\"-PT1M30S\"".to_string());
+
+        let Tester(dur) = serde_json::from_str(&json).unwrap();
+        assert_eq!(dur, negative);
+    }
+
+    /// A minimal [`SeqAccess`] over a fixed `(bool, u64, u32)` triple, used
+    /// to drive [`SignedDurationTupleVisitor`] directly without a real
+    /// non-human-readable format (e.g. bincode) available as a
+    /// dev-dependency.
+    struct TupleSeqAccess(std::vec::IntoIter<serde_json::Value>);
+
+    impl<'de> SeqAccess<'de> for TupleSeqAccess {
+        type Error = serde_json::Error;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            match self.0.next() {
+                Some(value) => seed.deserialize(value).map(Some),
+                None => Ok(None),
+            }
         }
     }
+
+    #[test]
+    fn signed_chrono_duration_round_trips_tuple_encoding() {
+        // Mirrors exactly what `serialize_as` feeds into the tuple for the
+        // non-human-readable branch, then decodes it back through the real
+        // `SignedDurationTupleVisitor` used by `deserialize_as`.
+        let negative = chrono::Duration::seconds(-90);
+        let is_negative = negative < chrono::Duration::zero();
+        let magnitude = negative.abs().to_std().unwrap();
+
+        let seq = TupleSeqAccess(
+            vec![
+                serde_json::Value::from(is_negative),
+                serde_json::Value::from(magnitude.as_secs()),
+                serde_json::Value::from(magnitude.subsec_nanos()),
+            ]
+            .into_iter(),
+        );
+
+        let (decoded_negative, decoded_magnitude) =
+            SignedDurationTupleVisitor.visit_seq(seq).unwrap();
+        assert_eq!(decoded_negative, is_negative);
+        assert_eq!(decoded_magnitude, magnitude);
+
+        let reconstructed = chrono::Duration::from_std(decoded_magnitude).unwrap();
+        let reconstructed = if decoded_negative {
+            -reconstructed
+        } else {
+            reconstructed
+        };
+        assert_eq!(reconstructed, negative);
+    }
 }